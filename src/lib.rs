@@ -6,9 +6,19 @@
 //! ```
 //! to the enum, returning the precise identifier of the variant
 //! (“A”, “B”, …) and usable in `const` contexts.
+//!
+//! The returned string may be transformed with a container attribute:
+//! ```ignore
+//! #[derive(EnumVariantNameConst)]
+//! #[enum_variant_name(rename_all = "snake_case")]
+//! enum E { HttpRequest }
+//! // E::HttpRequest.variant_name() == "http_request"
+//! ```
+//! Because the transformation happens at macro-expansion time the result is
+//! emitted as a plain string literal, so `variant_name()` stays `const`.
 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{Data, DeriveInput, Fields, parse_macro_input};
 
 /// Derive macro that injects a
@@ -32,9 +42,18 @@ use syn::{Data, DeriveInput, Fields, parse_macro_input};
 /// const NAME: &str = MyEnum::B { x: 1, y: 2 }.variant_name();
 /// assert_eq!(NAME, "B");
 /// ```
-#[proc_macro_derive(EnumVariantNameConst)]
+#[proc_macro_derive(EnumVariantNameConst, attributes(enum_variant_name, variant_name))]
 pub fn enum_variant_name_const_derive(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Fallible body of the derive, so attribute-parsing errors can be surfaced
+/// as `compile_error!` the same way the “only enums” check is.
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let enum_ident = &input.ident;
     let generics = &input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -43,37 +62,405 @@ pub fn enum_variant_name_const_derive(item: TokenStream) -> TokenStream {
     let data_enum = match &input.data {
         Data::Enum(data) => data,
         _ => {
-            return syn::Error::new_spanned(
+            return Err(syn::Error::new_spanned(
                 enum_ident,
                 "`EnumVariantNameConst` can only be derived for enums",
-            )
-            .to_compile_error()
-            .into();
+            ));
         }
     };
 
-    // One match arm per variant, with the correct pattern shape.
-    let match_arms = data_enum.variants.iter().map(|v| {
-        let ident = &v.ident;
-        let pat = match &v.fields {
+    // Container-level options.
+    let container = parse_container_opts(&input.attrs)?;
+    let rename_all = container.rename_all;
+
+    // Resolve the per-variant options and output name once, in declaration
+    // order. The name is known at expansion time, so it is emitted as a literal
+    // to keep the generated code usable in `const` contexts. Discriminant codes
+    // follow the C-style rule: an explicit `code` sets the value and the next
+    // variants count up from there, unspecified ones start from `0`.
+    let mut variants: Vec<ResolvedVariant> = Vec::with_capacity(data_enum.variants.len());
+    let mut next_code: i64 = 0;
+    for v in &data_enum.variants {
+        let opts = parse_variant_opts(&v.attrs)?;
+        let name = match (opts.rename, rename_all) {
+            // An explicit `rename` overrides both the identifier and any
+            // container-level `rename_all`.
+            (Some(name), _) => name,
+            (None, Some(style)) => convert_case(&v.ident.to_string(), style),
+            (None, None) => v.ident.to_string(),
+        };
+        let code = opts.code.unwrap_or(next_code);
+        next_code = code + 1;
+        variants.push(ResolvedVariant {
+            variant: v,
+            name: syn::LitStr::new(&name, v.ident.span()),
+            skip: opts.skip,
+            code,
+        });
+    }
+
+    // No two variants may share a discriminant code.
+    for (i, rv) in variants.iter().enumerate() {
+        if let Some(prev) = variants[..i].iter().find(|o| o.code == rv.code) {
+            return Err(syn::Error::new_spanned(
+                rv.variant,
+                format!(
+                    "variant `{}` has the same code ({}) as variant `{}`",
+                    rv.variant.ident, rv.code, prev.variant.ident,
+                ),
+            ));
+        }
+    }
+
+    // One match arm per variant, with the correct pattern shape. `skip` only
+    // affects the generated enumeration/parsing surfaces (`VARIANT_NAMES`,
+    // `VARIANT_CODES`, `from_variant_name`, `from_variant_code`); the by-value
+    // accessors `variant_name`/`variant_code` still answer for every variant so
+    // the matches stay exhaustive.
+    let match_arms = variants.iter().map(|rv| {
+        let ident = &rv.variant.ident;
+        let name_lit = &rv.name;
+        let pat = match &rv.variant.fields {
             Fields::Named(_) => quote! { Self::#ident { .. } },
             Fields::Unnamed(_) => quote! { Self::#ident ( .. ) },
             Fields::Unit => quote! { Self::#ident },
         };
-        quote! { #pat => stringify!(#ident), }
+        quote! { #pat => #name_lit, }
     });
 
-    let expanded = quote! {
+    // `VARIANT_NAMES` / `VARIANT_COUNT` only list the variants that opted in.
+    let names: Vec<&syn::LitStr> = variants
+        .iter()
+        .filter(|rv| !rv.skip)
+        .map(|rv| &rv.name)
+        .collect();
+    let variant_count = names.len();
+
+    // Opt-in `is_<variant>()` predicates, one per variant. The method name is
+    // the snake-cased identifier, using the same word-splitter as `rename_all`.
+    let predicates = if container.predicates {
+        let methods = variants.iter().map(|rv| {
+            let ident = &rv.variant.ident;
+            let fn_name = format_ident!("is_{}", convert_case(&ident.to_string(), RenameAll::Snake));
+            let pat = match &rv.variant.fields {
+                Fields::Named(_) => quote! { Self::#ident { .. } },
+                Fields::Unnamed(_) => quote! { Self::#ident ( .. ) },
+                Fields::Unit => quote! { Self::#ident },
+            };
+            let doc = format!("Returns `true` if this value is `{ident}`.");
+            quote! {
+                #[doc = #doc]
+                #[inline(always)]
+                pub const fn #fn_name(&self) -> bool {
+                    match self {
+                        #pat => true,
+                        #[allow(unreachable_patterns)]
+                        _ => false,
+                    }
+                }
+            }
+        });
+        quote! { #( #methods )* }
+    } else {
+        quote! {}
+    };
+
+    // Const name→variant parsing, only possible when every variant is a unit
+    // variant (otherwise the constructor would need field values).
+    let all_unit = variants
+        .iter()
+        .all(|rv| matches!(rv.variant.fields, Fields::Unit));
+    let from_variant_name = if all_unit {
+        let arms = variants.iter().filter(|rv| !rv.skip).map(|rv| {
+            let ident = &rv.variant.ident;
+            let name_lit = &rv.name;
+            quote! {
+                if bytes_eq(bytes, #name_lit.as_bytes()) {
+                    return ::core::option::Option::Some(Self::#ident);
+                }
+            }
+        });
+        quote! {
+            /// Parse a variant from its (possibly renamed) name, the inverse of
+            /// [`Self::variant_name`]. Returns `None` if no variant matches;
+            /// skipped variants are never returned.
+            pub const fn from_variant_name(name: &str) -> ::core::option::Option<Self> {
+                // `str`/`[u8]` equality isn't `const`-comparable directly, so
+                // compare length then byte-by-byte.
+                const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+                    if a.len() != b.len() {
+                        return false;
+                    }
+                    let mut i = 0;
+                    while i < a.len() {
+                        if a[i] != b[i] {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+                let bytes = name.as_bytes();
+                #( #arms )*
+                ::core::option::Option::None
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // `variant_code()` — the numeric twin of `variant_name()`.
+    let code_arms = variants.iter().map(|rv| {
+        let ident = &rv.variant.ident;
+        let code = rv.code;
+        let pat = match &rv.variant.fields {
+            Fields::Named(_) => quote! { Self::#ident { .. } },
+            Fields::Unnamed(_) => quote! { Self::#ident ( .. ) },
+            Fields::Unit => quote! { Self::#ident },
+        };
+        quote! { #pat => #code, }
+    });
+
+    // `VARIANT_CODES` pairs each non-skipped variant's code with its name, in
+    // declaration order.
+    let code_pairs = variants.iter().filter(|rv| !rv.skip).map(|rv| {
+        let code = rv.code;
+        let name_lit = &rv.name;
+        quote! { (#code, #name_lit) }
+    });
+
+    // `from_variant_code()` — only for unit-only enums, like `from_variant_name`.
+    let from_variant_code = if all_unit {
+        let arms = variants.iter().filter(|rv| !rv.skip).map(|rv| {
+            let ident = &rv.variant.ident;
+            let code = rv.code;
+            quote! { #code => ::core::option::Option::Some(Self::#ident), }
+        });
+        quote! {
+            /// Parse a variant from its stable code, the inverse of
+            /// [`Self::variant_code`]. Returns `None` if no variant matches;
+            /// skipped variants are never returned.
+            pub const fn from_variant_code(code: i64) -> ::core::option::Option<Self> {
+                match code {
+                    #( #arms )*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
         impl #impl_generics #enum_ident #ty_generics #where_clause {
-            /// Compile-time string with the variant’s identifier.
+            /// The number of non-skipped variants, i.e. `VARIANT_NAMES.len()`.
+            pub const VARIANT_COUNT: usize = #variant_count;
+
+            /// Every non-skipped variant's stable code paired with its name, in
+            /// declaration order.
+            pub const VARIANT_CODES: &'static [(i64, &'static str)] =
+                &[ #( #code_pairs ),* ];
+
+            /// Every non-skipped variant’s (possibly renamed) name, in
+            /// declaration order.
+            pub const VARIANT_NAMES: &'static [&'static str] = &[ #( #names ),* ];
+
+            /// Compile-time string with the variant’s (possibly renamed) name.
             #[inline(always)]
             pub const fn variant_name(&self) -> &'static str {
                 match self {
                     #( #match_arms )*
                 }
             }
+
+            /// Compile-time stable code for this variant.
+            #[inline(always)]
+            pub const fn variant_code(&self) -> i64 {
+                match self {
+                    #( #code_arms )*
+                }
+            }
+
+            #predicates
+
+            #from_variant_name
+
+            #from_variant_code
         }
-    };
+    })
+}
+
+/// A variant together with its resolved output name and options.
+struct ResolvedVariant<'a> {
+    variant: &'a syn::Variant,
+    name: syn::LitStr,
+    skip: bool,
+    code: i64,
+}
+
+/// Options parsed from a variant-level `#[variant_name(...)]` attribute.
+#[derive(Default)]
+struct VariantOpts {
+    rename: Option<String>,
+    skip: bool,
+    code: Option<i64>,
+}
+
+/// Read the `#[variant_name(rename = "...")]` / `#[variant_name(skip)]`
+/// options from a single variant, rejecting unknown keys.
+fn parse_variant_opts(attrs: &[syn::Attribute]) -> syn::Result<VariantOpts> {
+    let mut opts = VariantOpts::default();
+    for attr in attrs {
+        if !attr.path().is_ident("variant_name") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                opts.rename = Some(lit.value());
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                opts.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("code") {
+                let lit: syn::LitInt = meta.value()?.parse()?;
+                opts.code = Some(lit.base10_parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown `variant_name` option"))
+            }
+        })?;
+    }
+    Ok(opts)
+}
+
+/// The case styles accepted by `rename_all`.
+#[derive(Clone, Copy)]
+enum RenameAll {
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    Camel,
+    Pascal,
+}
+
+/// Options parsed from the container-level `#[enum_variant_name(...)]`
+/// attribute.
+#[derive(Default)]
+struct ContainerOpts {
+    rename_all: Option<RenameAll>,
+    predicates: bool,
+}
+
+/// Read the container-level `#[enum_variant_name(...)]` options, rejecting
+/// unknown keys and `rename_all` styles.
+fn parse_container_opts(attrs: &[syn::Attribute]) -> syn::Result<ContainerOpts> {
+    let mut opts = ContainerOpts::default();
+    for attr in attrs {
+        // `variant_name` is a variant-level attribute; flag it at container
+        // scope instead of silently ignoring the misplacement.
+        if attr.path().is_ident("variant_name") {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`variant_name` is a variant-level attribute; use `enum_variant_name` on the enum",
+            ));
+        }
+        if !attr.path().is_ident("enum_variant_name") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                let style = match lit.value().as_str() {
+                    "snake_case" => RenameAll::Snake,
+                    "SCREAMING_SNAKE_CASE" => RenameAll::ScreamingSnake,
+                    "kebab-case" => RenameAll::Kebab,
+                    "camelCase" => RenameAll::Camel,
+                    "PascalCase" => RenameAll::Pascal,
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            &lit,
+                            format!("unknown `rename_all` style `{other}`"),
+                        ));
+                    }
+                };
+                opts.rename_all = Some(style);
+                Ok(())
+            } else if meta.path.is_ident("predicates") {
+                opts.predicates = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown `enum_variant_name` option"))
+            }
+        })?;
+    }
+    Ok(opts)
+}
+
+/// Split an identifier into its constituent words, treating a
+/// lowercase→uppercase transition, a letter→digit boundary and explicit `_`
+/// separators as word boundaries while keeping a run of capitals (like
+/// `HTTP`) together.
+fn split_words(ident: &str) -> Vec<String> {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for i in 0..chars.len() {
+        let c = chars[i];
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if !word.is_empty() {
+            let prev = chars[i - 1];
+            let boundary =
+                // `aA` — a run of lower-case (or digits) ends.
+                (!prev.is_ascii_uppercase() && c.is_ascii_uppercase())
+                // `a1` — a letter is followed by a digit.
+                || (prev.is_ascii_alphabetic() && c.is_ascii_digit())
+                // `HTTPRequest` — a run of capitals ends just before the last
+                // capital that starts the next word.
+                || (prev.is_ascii_uppercase()
+                    && c.is_ascii_uppercase()
+                    && i + 1 < chars.len()
+                    && chars[i + 1].is_ascii_lowercase());
+            if boundary {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// Capitalise a single word (`http` → `Http`), lower-casing the tail.
+fn capitalize(word: &str) -> String {
+    let lower = word.to_ascii_lowercase();
+    let mut chars = lower.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
 
-    TokenStream::from(expanded)
+/// Transform an identifier into the requested case style.
+fn convert_case(ident: &str, style: RenameAll) -> String {
+    let words = split_words(ident);
+    let lower: Vec<String> = words.iter().map(|w| w.to_ascii_lowercase()).collect();
+    match style {
+        RenameAll::Snake => lower.join("_"),
+        RenameAll::ScreamingSnake => lower.join("_").to_ascii_uppercase(),
+        RenameAll::Kebab => lower.join("-"),
+        RenameAll::Camel => lower
+            .iter()
+            .enumerate()
+            .map(|(i, w)| if i == 0 { w.clone() } else { capitalize(w) })
+            .collect(),
+        RenameAll::Pascal => lower.iter().map(|w| capitalize(w)).collect(),
+    }
 }
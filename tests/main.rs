@@ -107,3 +107,173 @@ fn exhaustive_match_compiles() {
     }
     assert_eq!(collected, ["A", "B", "C"]);
 }
+
+//
+// 6.  `rename_all` case conversion, including acronym and digit splitting.
+//
+
+#[derive(EnumVariantNameConst)]
+#[enum_variant_name(rename_all = "snake_case")]
+enum Snake {
+    HttpRequest,
+    HTTPRequest,
+    Utf8Error,
+}
+
+#[derive(EnumVariantNameConst)]
+#[enum_variant_name(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Screaming {
+    HttpRequest,
+}
+
+#[derive(EnumVariantNameConst)]
+#[enum_variant_name(rename_all = "kebab-case")]
+enum Kebab {
+    HttpRequest,
+}
+
+#[derive(EnumVariantNameConst)]
+#[enum_variant_name(rename_all = "camelCase")]
+enum Camel {
+    HttpRequest,
+}
+
+#[derive(EnumVariantNameConst)]
+#[enum_variant_name(rename_all = "PascalCase")]
+enum Pascal {
+    HttpRequest,
+}
+
+#[test]
+fn rename_all_styles() {
+    assert_eq!(Snake::HttpRequest.variant_name(), "http_request");
+    assert_eq!(Snake::HTTPRequest.variant_name(), "http_request");
+    assert_eq!(Snake::Utf8Error.variant_name(), "utf_8_error");
+    assert_eq!(Screaming::HttpRequest.variant_name(), "HTTP_REQUEST");
+    assert_eq!(Kebab::HttpRequest.variant_name(), "http-request");
+    assert_eq!(Camel::HttpRequest.variant_name(), "httpRequest");
+    assert_eq!(Pascal::HttpRequest.variant_name(), "HttpRequest");
+}
+
+//
+// 7.  `VARIANT_COUNT` / `VARIANT_NAMES`.
+//
+
+#[test]
+fn variant_names_constant() {
+    assert_eq!(Basic::VARIANT_COUNT, 3);
+    assert_eq!(Basic::VARIANT_NAMES, &["Unit", "Tuple", "Struct"]);
+    assert_eq!(Snake::VARIANT_NAMES, &["http_request", "http_request", "utf_8_error"]);
+}
+
+//
+// 8.  Per-variant `rename` and `skip`.
+//
+
+#[derive(EnumVariantNameConst)]
+#[enum_variant_name(rename_all = "snake_case")]
+enum Fine {
+    #[variant_name(rename = "ok")]
+    Okay,
+    Retrying,
+    #[variant_name(skip)]
+    Internal,
+}
+
+#[test]
+fn per_variant_attrs() {
+    // `rename` overrides both the identifier and the container `rename_all`.
+    assert_eq!(Fine::Okay.variant_name(), "ok");
+    assert_eq!(Fine::Retrying.variant_name(), "retrying");
+    // `skip` still answers by value but is omitted from the enumerations.
+    assert_eq!(Fine::Internal.variant_name(), "internal");
+    assert_eq!(Fine::VARIANT_NAMES, &["ok", "retrying"]);
+    assert_eq!(Fine::VARIANT_COUNT, 2);
+}
+
+//
+// 9.  `is_<variant>()` predicates (opt-in).
+//
+
+#[derive(EnumVariantNameConst)]
+#[enum_variant_name(predicates)]
+enum TestEnum {
+    A,
+    B { x: u8 },
+    C(i32, i32),
+    HttpRequest,
+}
+
+#[test]
+fn predicates() {
+    assert!(TestEnum::C(1, 2).is_c());
+    assert!(!TestEnum::A.is_c());
+    assert!(TestEnum::A.is_a());
+    assert!(TestEnum::B { x: 0 }.is_b());
+    assert!(TestEnum::HttpRequest.is_http_request());
+    // Works in `const` contexts.
+    const { assert!(TestEnum::A.is_a()) }
+}
+
+//
+// 10.  `from_variant_name` for unit-only enums.
+//
+
+#[derive(EnumVariantNameConst)]
+#[enum_variant_name(rename_all = "snake_case")]
+enum Color {
+    Red,
+    DarkGreen,
+    #[variant_name(skip)]
+    Hidden,
+}
+
+#[test]
+fn from_variant_name_roundtrips() {
+    assert!(matches!(Color::from_variant_name("red"), Some(Color::Red)));
+    assert!(matches!(
+        Color::from_variant_name("dark_green"),
+        Some(Color::DarkGreen)
+    ));
+    assert!(Color::from_variant_name("DarkGreen").is_none());
+    // Skipped variants are not parseable.
+    assert!(Color::from_variant_name("hidden").is_none());
+    // Usable in `const` contexts.
+    const RED: Option<Color> = Color::from_variant_name("red");
+    assert!(matches!(RED, Some(Color::Red)));
+}
+
+//
+// 11.  Stable discriminant codes and name↔code lookups.
+//
+
+#[derive(EnumVariantNameConst)]
+enum Wire {
+    #[variant_name(code = 7)]
+    Alpha,
+    Beta,
+    #[variant_name(code = 20)]
+    Gamma,
+    Delta,
+}
+
+#[test]
+fn variant_codes() {
+    // Explicit codes, with unspecified variants counting up from the previous.
+    assert_eq!(Wire::Alpha.variant_code(), 7);
+    assert_eq!(Wire::Beta.variant_code(), 8);
+    assert_eq!(Wire::Gamma.variant_code(), 20);
+    assert_eq!(Wire::Delta.variant_code(), 21);
+
+    assert_eq!(
+        Wire::VARIANT_CODES,
+        &[(7, "Alpha"), (8, "Beta"), (20, "Gamma"), (21, "Delta")]
+    );
+
+    assert!(matches!(Wire::from_variant_code(20), Some(Wire::Gamma)));
+    assert!(Wire::from_variant_code(0).is_none());
+
+    // Usable in `const` contexts.
+    const CODE: i64 = Wire::Beta.variant_code();
+    assert_eq!(CODE, 8);
+}